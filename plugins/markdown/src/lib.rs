@@ -15,14 +15,11 @@ pub fn activate() {
     console_log("Markdown Renderer activated");
 
     // Register handler for content.changed event
-    register_event("content.changed", |data| {
+    register_event_typed(EventType::ContentChanged, |event| {
         // Extract content from event data
-        let content = match from_value::<serde_json::Value>(data) {
-            Ok(val) => val.get("content")
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_string(),
-            Err(_) => String::new()
+        let content = match event {
+            Event::ContentChanged { content, .. } => content,
+            _ => return JsValue::NULL,
         };
 
         if content.is_empty() {
@@ -40,6 +37,12 @@ pub fn activate() {
 
         to_value(&result).unwrap_or(JsValue::NULL)
     });
+
+    // React when the Tag Manager republishes its tag set
+    register_event_as::<Vec<String>, _>("tags.updated", |tags| {
+        console_log(&format!("Tag Manager reported {} tag(s)", tags.len()));
+        JsValue::NULL
+    });
 }
 
 /// Plugin deactivation - called before plugin is unloaded
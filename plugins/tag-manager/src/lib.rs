@@ -14,14 +14,11 @@ pub fn activate() {
     console_log("Tag Manager activated");
 
     // Register handler for content.changed event
-    register_event("content.changed", |data| {
+    register_event_typed(EventType::ContentChanged, |event| {
         // Extract content from event data
-        let content = match from_value::<serde_json::Value>(data) {
-            Ok(val) => val.get("content")
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_string(),
-            Err(_) => String::new()
+        let content = match event {
+            Event::ContentChanged { content, .. } => content,
+            _ => return JsValue::NULL,
         };
 
         if content.is_empty() {
@@ -31,6 +28,9 @@ pub fn activate() {
         // Extract tags
         let tags = extract_tags_internal(&content);
 
+        // Let other plugins (e.g. Markdown Renderer) react to the new tag set
+        emit("tags.updated", &tags);
+
         // Return result
         let result = TagsResult {
             result_type: "tags".to_string(),
@@ -22,14 +22,11 @@ pub fn activate() {
     console_log("Word Counter activated");
 
     // Register handler for content.changed event
-    register_event("content.changed", |data| {
+    register_event_typed(EventType::ContentChanged, |event| {
         // Extract content from event data
-        let content = match from_value::<serde_json::Value>(data) {
-            Ok(val) => val.get("content")
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_string(),
-            Err(_) => String::new()
+        let content = match event {
+            Event::ContentChanged { content, .. } => content,
+            _ => return JsValue::NULL,
         };
 
         if content.is_empty() {
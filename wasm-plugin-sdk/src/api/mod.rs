@@ -5,8 +5,10 @@
  * Access is controlled by capabilities declared in the plugin manifest.
  */
 
+pub mod storage;
 pub mod text;
 pub mod ui;
 
+pub use storage::*;
 pub use text::*;
 pub use ui::*;
@@ -0,0 +1,127 @@
+/*!
+ * Storage API - Persist plugin state across sessions
+ *
+ * Requires permissions:
+ * - `storage.read` - Read and list persisted keys
+ * - `storage.write` - Write and delete persisted keys
+ *
+ * The [`fs`] submodule additionally requires `fs.watch` to observe and read
+ * files outside the editor buffer.
+ */
+
+use crate::capabilities::{self, Permission};
+use crate::PluginResult;
+use serde::Serialize;
+use serde_wasm_bindgen::{from_value, to_value};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = pluginAPI, js_name = callHostAPI, catch)]
+    fn call_host_api(namespace: &str, method: &str, args: JsValue) -> Result<JsValue, JsValue>;
+}
+
+#[derive(Serialize)]
+struct KeyArgs<'a> {
+    key: &'a str,
+}
+
+#[derive(Serialize)]
+struct SetArgs<'a> {
+    key: &'a str,
+    value: &'a [u8],
+}
+
+#[derive(Serialize)]
+struct PrefixArgs<'a> {
+    prefix: &'a str,
+}
+
+/// Storage API - Persist plugin state across sessions
+pub struct StorageAPI;
+
+impl StorageAPI {
+    /// Create a new StorageAPI instance
+    pub fn new() -> Self {
+        StorageAPI
+    }
+
+    /// Get a value by key, or `None` if it isn't set
+    ///
+    /// Requires permission: `storage.read`
+    pub fn get(&self, key: &str) -> PluginResult<Option<Vec<u8>>> {
+        capabilities::require(Permission::StorageRead)?;
+        let args = to_value(&KeyArgs { key }).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let result = crate::host::reject_async_result(call_host_api("storage", "get", args)?)?;
+        from_value(result).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// Set a value by key
+    ///
+    /// Requires permission: `storage.write`
+    pub fn set(&self, key: &str, value: &[u8]) -> PluginResult<()> {
+        capabilities::require(Permission::StorageWrite)?;
+        let args = to_value(&SetArgs { key, value }).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        crate::host::reject_async_result(call_host_api("storage", "set", args)?)?;
+        Ok(())
+    }
+
+    /// Delete a value by key
+    ///
+    /// Requires permission: `storage.write`
+    pub fn delete(&self, key: &str) -> PluginResult<()> {
+        capabilities::require(Permission::StorageWrite)?;
+        let args = to_value(&KeyArgs { key }).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        crate::host::reject_async_result(call_host_api("storage", "delete", args)?)?;
+        Ok(())
+    }
+
+    /// List stored keys matching a prefix
+    ///
+    /// Requires permission: `storage.read`
+    pub fn list_keys(&self, prefix: &str) -> PluginResult<Vec<String>> {
+        capabilities::require(Permission::StorageRead)?;
+        let args = to_value(&PrefixArgs { prefix }).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let result = crate::host::reject_async_result(call_host_api("storage", "listKeys", args)?)?;
+        from_value(result).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+impl Default for StorageAPI {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Read-only filesystem access, for plugins that react to changes outside the editor buffer
+pub mod fs {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct PathArgs<'a> {
+        path: &'a str,
+    }
+
+    /// Watch a file for external changes
+    ///
+    /// The host delivers change notifications as a `file.saved`-style event
+    /// rather than a callback, so the watch persists across plugin calls.
+    ///
+    /// Requires permission: `fs.watch`
+    pub fn watch(path: &str) -> PluginResult<()> {
+        capabilities::require(Permission::FsWatch)?;
+        let args = to_value(&PathArgs { path }).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        crate::host::reject_async_result(call_host_api("fs", "watch", args)?)?;
+        Ok(())
+    }
+
+    /// Read a file's contents from disk
+    ///
+    /// Requires permission: `fs.watch`
+    pub fn read_file(path: &str) -> PluginResult<Vec<u8>> {
+        capabilities::require(Permission::FsWatch)?;
+        let args = to_value(&PathArgs { path }).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let result = crate::host::reject_async_result(call_host_api("fs", "readFile", args)?)?;
+        from_value(result).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
@@ -4,14 +4,32 @@
  * Requires permissions:
  * - `text.read` - Read content from the editor
  * - `text.transform` - Modify content in the editor
+ *
+ * Every method round-trips through `callHostAPI`, which the host must
+ * resolve synchronously (see [`crate::host`]) — a WASM call into JS that
+ * itself awaits can't be un-awaited here.
  */
 
+use crate::capabilities::{self, Permission};
+use crate::PluginResult;
+use serde::Serialize;
+use serde_wasm_bindgen::{from_value, to_value};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(js_namespace = pluginAPI, js_name = callHostAPI)]
-    fn call_host_api(namespace: &str, method: &str, args: JsValue) -> JsValue;
+    #[wasm_bindgen(js_namespace = pluginAPI, js_name = callHostAPI, catch)]
+    fn call_host_api(namespace: &str, method: &str, args: JsValue) -> Result<JsValue, JsValue>;
+}
+
+#[derive(Serialize)]
+struct ReplaceContentArgs<'a> {
+    content: &'a str,
+}
+
+#[derive(Serialize)]
+struct InsertAtCursorArgs<'a> {
+    text: &'a str,
 }
 
 /// Text API - Access and manipulate editor content
@@ -26,32 +44,40 @@ impl TextAPI {
     /// Get the current content from the editor
     ///
     /// Requires permission: `text.read`
-    pub fn get_content(&self) -> String {
-        // For now, this will be passed as argument to plugin functions
-        // In a full implementation, this would call the host API
-        String::new()
+    pub fn get_content(&self) -> PluginResult<String> {
+        capabilities::require(Permission::TextRead)?;
+        let result = crate::host::reject_async_result(call_host_api("text", "getContent", JsValue::NULL)?)?;
+        from_value(result).map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
     /// Get the current selection
     ///
     /// Requires permission: `text.read`
-    pub fn get_selection(&self) -> String {
-        String::new()
+    pub fn get_selection(&self) -> PluginResult<String> {
+        capabilities::require(Permission::TextRead)?;
+        let result = crate::host::reject_async_result(call_host_api("text", "getSelection", JsValue::NULL)?)?;
+        from_value(result).map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
     /// Replace all content in the editor
     ///
     /// Requires permission: `text.transform`
-    pub fn replace_content(&self, new_content: &str) -> Result<(), JsValue> {
-        // Would call host API in full implementation
+    pub fn replace_content(&self, new_content: &str) -> PluginResult<()> {
+        capabilities::require(Permission::TextTransform)?;
+        let args = to_value(&ReplaceContentArgs { content: new_content })
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        crate::host::reject_async_result(call_host_api("text", "replaceContent", args)?)?;
         Ok(())
     }
 
     /// Insert text at the current cursor position
     ///
     /// Requires permission: `text.transform`
-    pub fn insert_at_cursor(&self, text: &str) -> Result<(), JsValue> {
-        // Would call host API in full implementation
+    pub fn insert_at_cursor(&self, text: &str) -> PluginResult<()> {
+        capabilities::require(Permission::TextTransform)?;
+        let args = to_value(&InsertAtCursorArgs { text })
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        crate::host::reject_async_result(call_host_api("text", "insertAtCursor", args)?)?;
         Ok(())
     }
 }
@@ -4,10 +4,35 @@
  * Requires permissions:
  * - `ui.panel` - Update panel content
  * - `ui.statusBar` - Update status bar items
+ *
+ * Every method round-trips through `callHostAPI`, which the host must
+ * resolve synchronously (see [`crate::host`]).
  */
 
+use crate::capabilities::{self, Permission};
+use crate::PluginResult;
+use serde::Serialize;
+use serde_wasm_bindgen::to_value;
 use wasm_bindgen::prelude::*;
 
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = pluginAPI, js_name = callHostAPI, catch)]
+    fn call_host_api(namespace: &str, method: &str, args: JsValue) -> Result<JsValue, JsValue>;
+}
+
+#[derive(Serialize)]
+struct UpdatePanelArgs<'a> {
+    panel_id: &'a str,
+    html: &'a str,
+}
+
+#[derive(Serialize)]
+struct UpdateStatusBarArgs<'a> {
+    item_id: &'a str,
+    text: &'a str,
+}
+
 /// UI API - Control panels, status bar, and other UI elements
 pub struct UiAPI;
 
@@ -24,9 +49,11 @@ impl UiAPI {
     /// # Arguments
     /// * `panel_id` - The ID of the panel (from manifest contribution)
     /// * `html` - HTML content to display
-    pub fn update_panel(&self, panel_id: &str, html: &str) -> Result<(), JsValue> {
-        // Would call host API in full implementation
-        // For now, plugins return HTML directly
+    pub fn update_panel(&self, panel_id: &str, html: &str) -> PluginResult<()> {
+        capabilities::require(Permission::UiPanel)?;
+        let args = to_value(&UpdatePanelArgs { panel_id, html })
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        crate::host::reject_async_result(call_host_api("ui", "updatePanel", args)?)?;
         Ok(())
     }
 
@@ -37,8 +64,11 @@ impl UiAPI {
     /// # Arguments
     /// * `item_id` - The ID of the status bar item (from manifest contribution)
     /// * `text` - Text to display
-    pub fn update_status_bar(&self, item_id: &str, text: &str) -> Result<(), JsValue> {
-        // Would call host API in full implementation
+    pub fn update_status_bar(&self, item_id: &str, text: &str) -> PluginResult<()> {
+        capabilities::require(Permission::UiStatusBar)?;
+        let args = to_value(&UpdateStatusBarArgs { item_id, text })
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        crate::host::reject_async_result(call_host_api("ui", "updateStatusBar", args)?)?;
         Ok(())
     }
 }
@@ -0,0 +1,69 @@
+/*!
+ * Binary payload channel
+ *
+ * Parallel transport to the JSON event path: payloads cross the WASM
+ * boundary as bincode-encoded bytes instead of `serde_wasm_bindgen` JSON
+ * values, for plugins that move large documents on every event. The JSON
+ * path stays the default for compatibility; performance-sensitive plugins
+ * (e.g. a word counter on a multi-megabyte file) can opt into this one.
+ *
+ * This binds to its own `registerEventBinary`/`emitEventBinary` host
+ * imports rather than reusing `registerEvent`/`emitEvent` — the host needs
+ * to know which events are binary-framed so it can route raw bytes instead
+ * of a JSON value.
+ */
+
+use js_sys::Uint8Array;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::PluginResult;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = pluginAPI, js_name = registerEventBinary)]
+    fn registerEventBinary(eventName: &str, handler: &js_sys::Function);
+
+    #[wasm_bindgen(js_namespace = pluginAPI, js_name = emitEventBinary)]
+    fn emitEventBinary(eventName: &str, payload: JsValue, targetPluginId: JsValue);
+}
+
+/// Register an event handler that receives and returns bincode-encoded bytes
+///
+/// The payload arrives as a `Uint8Array`, is decoded with `bincode` into
+/// `T`, run through `handler`, and the `R` result is bincode-encoded back
+/// into a `Uint8Array`. Decode/encode failures yield `JsValue::NULL` rather
+/// than panicking across the WASM boundary.
+pub fn register_event_bincode<T, R, F>(event_name: &str, handler: F)
+where
+    T: DeserializeOwned,
+    R: Serialize,
+    F: Fn(T) -> R + 'static,
+{
+    let closure = Closure::wrap(Box::new(move |data: JsValue| -> JsValue {
+        let bytes = Uint8Array::new(&data).to_vec();
+
+        let decoded: T = match bincode::deserialize(&bytes) {
+            Ok(value) => value,
+            Err(_) => return JsValue::NULL,
+        };
+
+        let result = handler(decoded);
+
+        match bincode::serialize(&result) {
+            Ok(encoded) => Uint8Array::from(encoded.as_slice()).into(),
+            Err(_) => JsValue::NULL,
+        }
+    }) as Box<dyn Fn(JsValue) -> JsValue>);
+
+    registerEventBinary(event_name, closure.as_ref().unchecked_ref());
+    closure.forget();
+}
+
+/// Emit a bincode-encoded payload to the host and any subscribed plugins
+pub fn emit_bincode<T: Serialize>(event_name: &str, payload: &T) -> PluginResult<()> {
+    let encoded = bincode::serialize(payload).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    emitEventBinary(event_name, Uint8Array::from(encoded.as_slice()).into(), JsValue::NULL);
+    Ok(())
+}
@@ -0,0 +1,83 @@
+/*!
+ * Content-hash memoization for event handlers
+ *
+ * `content.changed` fires on every keystroke, and a naive handler re-parses
+ * the whole document each time even when nothing relevant changed. This
+ * wraps a handler so repeated invocations with an identical payload skip
+ * recomputation and return the cached result instead.
+ */
+
+use crate::register_event;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use wasm_bindgen::prelude::*;
+
+/// Number of recent results kept per cached handler
+const CACHE_SIZE: usize = 8;
+
+struct CacheEntry {
+    key: u64,
+    value: JsValue,
+}
+
+/// FNV-1a hash of a payload's JSON serialization, used as the memoization key
+fn hash_payload(data: &JsValue) -> u64 {
+    let json = js_sys::JSON::stringify(data)
+        .map(String::from)
+        .unwrap_or_default();
+
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in json.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Register an event handler whose result is memoized by a hash of its input
+///
+/// Keeps the last [`CACHE_SIZE`] results in a small LRU keyed by a 64-bit
+/// hash of the payload. If an incoming payload hashes to a cached entry, the
+/// handler is skipped and the cached `JsValue` is returned directly.
+///
+/// # Example
+/// ```rust
+/// register_event_cached("content.changed", |data| {
+///     // Only runs when the payload actually changed
+///     render_markdown_result(data)
+/// });
+/// ```
+pub fn register_event_cached<F>(event_name: &str, handler: F)
+where
+    F: Fn(JsValue) -> JsValue + 'static,
+{
+    let cache: RefCell<VecDeque<CacheEntry>> = RefCell::new(VecDeque::with_capacity(CACHE_SIZE));
+
+    register_event(event_name, move |data| {
+        let key = hash_payload(&data);
+
+        {
+            let mut entries = cache.borrow_mut();
+            if let Some(index) = entries.iter().position(|entry| entry.key == key) {
+                // Move the hit to the back so recency is tracked, not just insertion order
+                let entry = entries.remove(index).unwrap();
+                let value = entry.value.clone();
+                entries.push_back(entry);
+                return value;
+            }
+        }
+
+        let result = handler(data);
+
+        let mut entries = cache.borrow_mut();
+        if entries.len() == CACHE_SIZE {
+            entries.pop_front();
+        }
+        entries.push_back(CacheEntry {
+            key,
+            value: result.clone(),
+        });
+
+        result
+    });
+}
@@ -0,0 +1,116 @@
+/*!
+ * Capability-based permission enforcement
+ *
+ * `PluginManifest.permissions` used to be parsed and then ignored; every
+ * host-calling method in `api::text`/`api::ui` only *documented* the
+ * capability it needed. This module turns that honor system into an
+ * enforced one: [`init`] loads the manifest's declared permissions (on the
+ * first permission check, unless the plugin has already called it or
+ * [`load_manifest`]), and [`require`] gates each host call against them.
+ */
+
+use crate::PluginManifest;
+use serde_wasm_bindgen::from_value;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = pluginAPI, js_name = getManifest)]
+    fn getManifest() -> JsValue;
+}
+
+/// A capability a plugin may request in its manifest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Permission {
+    TextRead,
+    TextTransform,
+    UiPanel,
+    UiStatusBar,
+    StorageRead,
+    StorageWrite,
+    FsWatch,
+}
+
+impl Permission {
+    /// The manifest string this permission corresponds to (e.g. `"text.read"`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::TextRead => "text.read",
+            Permission::TextTransform => "text.transform",
+            Permission::UiPanel => "ui.panel",
+            Permission::UiStatusBar => "ui.statusBar",
+            Permission::StorageRead => "storage.read",
+            Permission::StorageWrite => "storage.write",
+            Permission::FsWatch => "fs.watch",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Permission> {
+        match raw {
+            "text.read" => Some(Permission::TextRead),
+            "text.transform" => Some(Permission::TextTransform),
+            "ui.panel" => Some(Permission::UiPanel),
+            "ui.statusBar" => Some(Permission::UiStatusBar),
+            "storage.read" => Some(Permission::StorageRead),
+            "storage.write" => Some(Permission::StorageWrite),
+            "fs.watch" => Some(Permission::FsWatch),
+            _ => None,
+        }
+    }
+}
+
+thread_local! {
+    static GRANTED: RefCell<HashSet<Permission>> = RefCell::new(HashSet::new());
+    static INITIALIZED: RefCell<bool> = RefCell::new(false);
+}
+
+/// Load a manifest's declared permissions into the capability set
+pub fn load_manifest(manifest: &PluginManifest) {
+    let granted: HashSet<Permission> = manifest
+        .permissions
+        .iter()
+        .filter_map(|p| Permission::parse(p))
+        .collect();
+
+    GRANTED.with(|cell| *cell.borrow_mut() = granted);
+    INITIALIZED.with(|cell| *cell.borrow_mut() = true);
+}
+
+/// Fetch this plugin's manifest from the host and load its permissions
+///
+/// Idempotent and safe to call more than once; [`require`] also calls this
+/// itself on first use, so plugins that forget to call it explicitly are
+/// still gated correctly rather than silently failing every check.
+pub fn init() {
+    if let Ok(manifest) = from_value::<PluginManifest>(getManifest()) {
+        load_manifest(&manifest);
+    }
+    INITIALIZED.with(|cell| *cell.borrow_mut() = true);
+}
+
+/// Check whether a capability has been granted by the manifest
+pub fn has_permission(permission: Permission) -> bool {
+    ensure_initialized();
+    GRANTED.with(|cell| cell.borrow().contains(&permission))
+}
+
+/// Return `Err` with a descriptive message if `permission` has not been granted
+pub(crate) fn require(permission: Permission) -> Result<(), JsValue> {
+    if has_permission(permission) {
+        Ok(())
+    } else {
+        Err(JsValue::from_str(&format!(
+            "permission {} not granted",
+            permission.as_str()
+        )))
+    }
+}
+
+fn ensure_initialized() {
+    let already_initialized = INITIALIZED.with(|cell| *cell.borrow());
+    if !already_initialized {
+        init();
+    }
+}
@@ -0,0 +1,231 @@
+/*!
+ * Typed event subsystem
+ *
+ * Wraps the host's untyped `registerEvent` JSON bridge in a strongly-typed
+ * `EventType`/`Event` pair, so plugins match on enum variants instead of
+ * digging through a `serde_json::Value` by hand.
+ */
+
+use crate::register_event;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_wasm_bindgen::{from_value, to_value};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = pluginAPI, js_name = emitEvent)]
+    fn emitEvent(eventName: &str, payload: JsValue, targetPluginId: JsValue);
+
+    // Returns an array of `{ pluginId, metadata }` entries, one per plugin
+    // subscribed to `eventName`. No closure crosses the boundary, so there is
+    // nothing here for the host to defer or store past this synchronous call.
+    #[wasm_bindgen(js_namespace = pluginAPI, js_name = getEventSubscribers)]
+    fn getEventSubscribers(eventName: &str) -> JsValue;
+
+    // Declares/withdraws interest in an event type without attaching a handler.
+    // Distinct from registerEvent/unregisterEvent, which bind the closure that
+    // actually runs when the event fires — subscribe/unsubscribe must not
+    // touch that binding.
+    #[wasm_bindgen(js_namespace = pluginAPI, js_name = subscribeEvent)]
+    fn subscribeEvent(eventName: &str);
+
+    #[wasm_bindgen(js_namespace = pluginAPI, js_name = unsubscribeEvent)]
+    fn unsubscribeEvent(eventName: &str);
+}
+
+/// Identifies a class of host event a plugin can subscribe to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EventType {
+    ContentChanged,
+    SelectionChanged,
+    FileOpened,
+    FileSaved,
+}
+
+impl EventType {
+    /// The wire name used by the host bridge (e.g. `"content.changed"`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventType::ContentChanged => "content.changed",
+            EventType::SelectionChanged => "selection.changed",
+            EventType::FileOpened => "file.opened",
+            EventType::FileSaved => "file.saved",
+        }
+    }
+}
+
+/// A decoded host event, carrying its payload as typed fields
+#[derive(Debug, Clone)]
+pub enum Event {
+    ContentChanged { content: String, path: Option<String> },
+    SelectionChanged { start: usize, end: usize },
+    FileOpened { path: String },
+    FileSaved { path: String },
+}
+
+#[derive(Deserialize)]
+struct ContentChangedPayload {
+    content: String,
+    path: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SelectionChangedPayload {
+    start: usize,
+    end: usize,
+}
+
+#[derive(Deserialize)]
+struct FileOpenedPayload {
+    path: String,
+}
+
+#[derive(Deserialize)]
+struct FileSavedPayload {
+    path: String,
+}
+
+impl Event {
+    fn decode(event_type: EventType, data: JsValue) -> Option<Event> {
+        match event_type {
+            EventType::ContentChanged => {
+                let payload: ContentChangedPayload = from_value(data).ok()?;
+                Some(Event::ContentChanged {
+                    content: payload.content,
+                    path: payload.path,
+                })
+            }
+            EventType::SelectionChanged => {
+                let payload: SelectionChangedPayload = from_value(data).ok()?;
+                Some(Event::SelectionChanged {
+                    start: payload.start,
+                    end: payload.end,
+                })
+            }
+            EventType::FileOpened => {
+                let payload: FileOpenedPayload = from_value(data).ok()?;
+                Some(Event::FileOpened { path: payload.path })
+            }
+            EventType::FileSaved => {
+                let payload: FileSavedPayload = from_value(data).ok()?;
+                Some(Event::FileSaved { path: payload.path })
+            }
+        }
+    }
+}
+
+/// Subscribe to one or more event types, without attaching a handler
+///
+/// Used to declare interest up front (e.g. so the host can route targeted
+/// `emit_to` deliveries); pair with [`register_event_typed`] to actually
+/// handle the events. This goes through the host's dedicated subscription
+/// bookkeeping, not `registerEvent`, so it can't clobber a handler already
+/// bound via [`register_event_typed`].
+pub fn subscribe(events: &[EventType]) {
+    for event_type in events {
+        subscribeEvent(event_type.as_str());
+    }
+}
+
+/// Unsubscribe from one or more event types previously passed to [`subscribe`]
+///
+/// Goes through the host's dedicated subscription bookkeeping, not
+/// `unregisterEvent`, so it can't tear down a handler bound via
+/// [`register_event_typed`].
+pub fn unsubscribe(events: &[EventType]) {
+    for event_type in events {
+        unsubscribeEvent(event_type.as_str());
+    }
+}
+
+/// Register a typed event handler at runtime
+///
+/// Decodes the host's JSON payload into an [`Event`] once inside the SDK, so
+/// the handler matches on enum variants instead of parsing untyped JSON.
+///
+/// # Example
+/// ```rust
+/// register_event_typed(EventType::ContentChanged, |event| {
+///     match event {
+///         Event::ContentChanged { content, .. } => JsValue::from_str(&content),
+///         _ => JsValue::NULL,
+///     }
+/// });
+/// ```
+pub fn register_event_typed<F>(event_type: EventType, handler: F)
+where
+    F: Fn(Event) -> JsValue + 'static,
+{
+    register_event(event_type.as_str(), move |data| match Event::decode(event_type, data) {
+        Some(event) => handler(event),
+        None => JsValue::NULL,
+    });
+}
+
+/// Register a handler for a plugin-defined event name, decoding its JSON payload into `T`
+///
+/// `EventType`/`Event` only cover the fixed set of events the host itself
+/// raises; a plugin-defined event emitted via [`emit`] (e.g. `"tags.updated"`)
+/// carries whatever shape that plugin chose, so it can't join the `Event`
+/// enum. This still keeps the JSON decoding inside the SDK instead of in
+/// each listening plugin.
+pub fn register_event_as<T, F>(event_name: &str, handler: F)
+where
+    T: DeserializeOwned,
+    F: Fn(T) -> JsValue + 'static,
+{
+    register_event(event_name, move |data| match from_value::<T>(data) {
+        Ok(value) => handler(value),
+        Err(_) => JsValue::NULL,
+    });
+}
+
+/// Emit an event to the host and any subscribed plugins
+///
+/// # Example
+/// ```rust
+/// emit("tags.updated", &tags);
+/// ```
+pub fn emit<T: Serialize>(event_name: &str, payload: &T) {
+    if let Ok(value) = to_value(payload) {
+        emitEvent(event_name, value, JsValue::NULL);
+    }
+}
+
+/// Emit an event delivered only to a single named plugin's listeners
+pub fn emit_to<T: Serialize>(target_plugin_id: &str, event_name: &str, payload: &T) {
+    if let Ok(value) = to_value(payload) {
+        emitEvent(event_name, value, JsValue::from_str(target_plugin_id));
+    }
+}
+
+/// Emit an event delivered only to subscribers for which `predicate` returns `true`
+///
+/// Fetches each subscriber's registered metadata from the host up front and
+/// evaluates `predicate` against it in Rust, then delivers individually via
+/// [`emit_to`] — rather than handing the host a closure to invoke whenever
+/// it pleases. That would make correctness depend on the host promising to
+/// call it synchronously, an assumption an FFI boundary can't enforce.
+pub fn emit_filter<T, F>(event_name: &str, payload: &T, predicate: F)
+where
+    T: Serialize,
+    F: Fn(JsValue) -> bool,
+{
+    let subscribers = js_sys::Array::from(&getEventSubscribers(event_name));
+
+    for subscriber in subscribers.iter() {
+        let plugin_id = js_sys::Reflect::get(&subscriber, &JsValue::from_str("pluginId"))
+            .ok()
+            .and_then(|id| id.as_string());
+
+        let metadata = js_sys::Reflect::get(&subscriber, &JsValue::from_str("metadata"))
+            .unwrap_or(JsValue::NULL);
+
+        let Some(plugin_id) = plugin_id else { continue };
+
+        if predicate(metadata) {
+            emit_to(&plugin_id, event_name, payload);
+        }
+    }
+}
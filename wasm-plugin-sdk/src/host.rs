@@ -0,0 +1,26 @@
+/*!
+ * Host call contract
+ *
+ * `pluginAPI.callHostAPI` is contractually synchronous: a WASM call into JS
+ * that itself awaits doesn't resolve before control returns to WASM, so the
+ * host must implement `callHostAPI` as a blocking request/response (e.g. a
+ * shared buffer armed with `Atomics.wait`), never as something that returns
+ * a `Promise`. [`reject_async_result`] enforces that contract at the SDK
+ * boundary instead of letting a `Promise` flow into `from_value` and fail
+ * with a confusing deserialization error.
+ */
+
+use wasm_bindgen::prelude::*;
+
+/// Fail loudly if a `callHostAPI` result is a `Promise` instead of a resolved value
+pub(crate) fn reject_async_result(result: JsValue) -> Result<JsValue, JsValue> {
+    if result.is_instance_of::<js_sys::Promise>() {
+        return Err(JsValue::from_str(
+            "callHostAPI must resolve synchronously, but the host returned a Promise. \
+             The host bridge must implement callHostAPI as a blocking request/response \
+             (e.g. a shared buffer + Atomics.wait), not an async callback.",
+        ));
+    }
+
+    Ok(result)
+}
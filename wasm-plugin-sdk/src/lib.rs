@@ -23,6 +23,11 @@
  */
 
 pub mod api;
+pub mod binary;
+pub mod cache;
+pub mod capabilities;
+pub mod events;
+pub(crate) mod host;
 pub mod prelude;
 
 pub use wasm_bindgen::prelude::*;
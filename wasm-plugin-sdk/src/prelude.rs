@@ -10,6 +10,13 @@
 
 pub use crate::{console_log, console_error, console_warn, PluginResult};
 pub use crate::api::*;
+pub use crate::binary::{emit_bincode, register_event_bincode};
+pub use crate::cache::register_event_cached;
+pub use crate::capabilities::{has_permission, init as init_capabilities, load_manifest, Permission};
+pub use crate::events::{
+    emit, emit_filter, emit_to, register_event_as, register_event_typed, subscribe, unsubscribe,
+    Event, EventType,
+};
 pub use wasm_bindgen::prelude::*;
 pub use serde::{Serialize, Deserialize};
 pub use serde_wasm_bindgen::{to_value, from_value};